@@ -0,0 +1,56 @@
+-- 研修管理システム データベーススキーマ ロールバック
+-- database-schema.sql で作成したオブジェクトをすべて削除する（マイグレーションの down に相当）
+-- 再実行しても失敗しないよう、すべて IF EXISTS を付ける
+
+-- ===== ビュー =====
+
+DROP VIEW IF EXISTS project_participant_interview_status;
+DROP VIEW IF EXISTS material_usage_stats;
+DROP VIEW IF EXISTS this_week_interviews;
+DROP VIEW IF EXISTS active_projects;
+
+-- ===== トリガー =====
+-- テーブル削除で連動して消えるが、関数を先に削除できるよう明示的に外す
+
+DROP TRIGGER IF EXISTS check_interview_project_participant_trigger ON interviews;
+DROP TRIGGER IF EXISTS check_project_participant_company_trigger ON project_participants;
+DROP TRIGGER IF EXISTS update_meetings_updated_at ON meetings;
+DROP TRIGGER IF EXISTS update_interviews_updated_at ON interviews;
+DROP TRIGGER IF EXISTS update_project_participants_updated_at ON project_participants;
+DROP TRIGGER IF EXISTS update_projects_updated_at ON projects;
+DROP TRIGGER IF EXISTS update_trainings_updated_at ON trainings;
+DROP TRIGGER IF EXISTS update_materials_updated_at ON materials;
+DROP TRIGGER IF EXISTS update_students_updated_at ON students;
+DROP TRIGGER IF EXISTS update_companies_updated_at ON companies;
+DROP TRIGGER IF EXISTS update_users_updated_at ON users;
+
+-- ===== 関数 =====
+-- テーブル削除では消えないため、個別に削除する
+
+DROP FUNCTION IF EXISTS check_interview_project_participant();
+DROP FUNCTION IF EXISTS check_project_participant_company();
+DROP FUNCTION IF EXISTS cleanup_expired_sessions();
+DROP FUNCTION IF EXISTS update_session_last_accessed();
+DROP FUNCTION IF EXISTS update_updated_at_column();
+
+-- ===== テーブル =====
+-- 外部キーの参照元から順に削除する（インデックス・一意制約・CHECK制約はテーブルと共に削除される）
+
+ALTER TABLE IF EXISTS users DROP CONSTRAINT IF EXISTS fk_users_company_id;
+
+DROP TABLE IF EXISTS audit_logs;
+DROP TABLE IF EXISTS role_permissions;
+DROP TABLE IF EXISTS meeting_note_revisions;
+DROP TABLE IF EXISTS meetings;
+DROP TABLE IF EXISTS interviews;
+DROP TABLE IF EXISTS project_participants;
+DROP TABLE IF EXISTS projects;
+DROP TABLE IF EXISTS training_materials;
+DROP TABLE IF EXISTS trainings;
+DROP TABLE IF EXISTS materials;
+DROP TABLE IF EXISTS students;
+DROP TABLE IF EXISTS companies;
+DROP TABLE IF EXISTS sessions;
+DROP TABLE IF EXISTS users;
+
+-- uuid-ossp 拡張は他スキーマと共有され得るため削除しない（up 側は IF NOT EXISTS で再作成に対応済み）
@@ -12,7 +12,10 @@ CREATE TABLE users (
     email VARCHAR(255) UNIQUE NOT NULL,
     name VARCHAR(255) NOT NULL,
     role VARCHAR(20) NOT NULL CHECK (role IN ('admin', 'trainer', 'instructor')),
+    company_id UUID, -- 所属企業（NULL=企業に属さない）。外部キーは companies 作成後に付与
     password_hash VARCHAR(255) NOT NULL,
+    failed_login_count INTEGER NOT NULL DEFAULT 0 CHECK (failed_login_count >= 0), -- 連続ログイン失敗回数
+    locked_until TIMESTAMP WITH TIME ZONE, -- アカウントロック解除日時（NULL=ロックなし）
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
 );
@@ -21,7 +24,12 @@ CREATE TABLE users (
 CREATE TABLE sessions (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
     user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-    session_token VARCHAR(255) UNIQUE NOT NULL,
+    session_token_hash CHAR(64) UNIQUE NOT NULL, -- セッショントークンのSHA-256（16進）。平文は保存しない
+    csrf_token VARCHAR(128) NOT NULL, -- OsRng 由来の base64url トークン
+    ip_address INET, -- ログイン元IP
+    user_agent TEXT,
+    is_persistent BOOLEAN NOT NULL DEFAULT FALSE, -- 「ログイン状態を保持」による長期セッション
+    rotation_required BOOLEAN NOT NULL DEFAULT FALSE, -- 権限変更後、次のリクエストでトークンを再生成する
     expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
     last_accessed_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
@@ -36,6 +44,7 @@ CREATE TABLE companies (
     contact_person VARCHAR(255) NOT NULL,
     contact_email VARCHAR(255) NOT NULL,
     chat_link TEXT,
+    max_trainings INTEGER CHECK (max_trainings >= 0), -- 企業限定研修の作成数上限（NULL は設定値 default_company_max）
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
 );
@@ -51,7 +60,7 @@ CREATE TABLE students (
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
 
-    UNIQUE(email, company_id) -- 同一企業内でのメール重複防止
+    CONSTRAINT idx_students_unique_email_company UNIQUE(email, company_id) -- 同一企業内でのメール重複防止
 );
 
 -- ===== 教材・研修管理 =====
@@ -60,7 +69,7 @@ CREATE TABLE students (
 CREATE TABLE materials (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
     title VARCHAR(255) NOT NULL,
-    url TEXT NOT NULL,
+    url TEXT NOT NULL, -- 正規化済みURL（アプリ層で正規化して保存）
     domain VARCHAR(255) NOT NULL, -- URLから自動抽出
     description TEXT NOT NULL,
     recommendation_level INTEGER NOT NULL CHECK (recommendation_level BETWEEN 1 AND 5),
@@ -79,6 +88,7 @@ CREATE TABLE trainings (
     completion_criteria TEXT NOT NULL, -- 完了条件
     company_id UUID REFERENCES companies(id) ON DELETE SET NULL, -- NULL=公開、UUID=企業限定
     created_by UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
+    deleted_at TIMESTAMP WITH TIME ZONE, -- ソフトデリート日時（NULL=有効）
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
 );
@@ -106,6 +116,8 @@ CREATE TABLE projects (
     title VARCHAR(255) NOT NULL,
     start_date DATE NOT NULL,
     end_date DATE NOT NULL,
+    max_interviews_per_participant INTEGER CHECK (max_interviews_per_participant > 0), -- NULL はシステム既定値
+    max_participants INTEGER CHECK (max_participants > 0), -- 受け入れ人数の上限（NULL は無制限）
     created_by UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
@@ -136,6 +148,7 @@ CREATE TABLE interviews (
     interviewer_id UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
     scheduled_at TIMESTAMP WITH TIME ZONE NOT NULL,
     status VARCHAR(20) NOT NULL DEFAULT 'scheduled' CHECK (status IN ('scheduled', 'completed', 'cancelled')),
+    reschedule_count INTEGER NOT NULL DEFAULT 0 CHECK (reschedule_count >= 0), -- 日時変更回数
     notes TEXT, -- Markdown形式の面談記録
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
@@ -154,6 +167,7 @@ CREATE TABLE meetings (
     instructor_id UUID REFERENCES users(id) ON DELETE SET NULL, -- 任意参加の研修講師
     notes TEXT, -- Markdown形式の研修記録
     created_by UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
+    updated_by UUID REFERENCES users(id) ON DELETE SET NULL, -- 最終更新者（記録履歴の更新者に使用）
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
 
@@ -164,6 +178,29 @@ CREATE TABLE meetings (
     )
 );
 
+-- 定例会記録の履歴テーブル（notes 更新時に旧内容を保存）
+CREATE TABLE meeting_note_revisions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    meeting_id UUID NOT NULL REFERENCES meetings(id) ON DELETE CASCADE,
+    notes TEXT, -- 更新前の記録（NULL=記録なしの状態から更新）
+    edited_by UUID REFERENCES users(id) ON DELETE SET NULL, -- この版を置き換えた更新者
+    edited_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- ===== 権限設定 =====
+
+-- ロール別の許可ルート（行が1件も無い場合はアプリのハードコード既定値を使う）
+CREATE TABLE role_permissions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    role VARCHAR(50) NOT NULL CHECK (role IN ('admin', 'trainer', 'instructor')),
+    route_pattern VARCHAR(255) NOT NULL, -- '/trainings/{id}' 形式のルートパターン
+    method VARCHAR(10) NOT NULL CHECK (method IN ('GET', 'POST', 'PUT', 'PATCH', 'DELETE')),
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP,
+
+    UNIQUE(role, route_pattern, method)
+);
+
 -- ===== 監査・ログ =====
 
 -- 監査ログテーブル
@@ -171,7 +208,10 @@ CREATE TABLE audit_logs (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
     user_id UUID REFERENCES users(id) ON DELETE SET NULL,
     action VARCHAR(100) NOT NULL, -- 'login', 'create_material', 'update_training', etc.
-    resource_type VARCHAR(50), -- 'user', 'material', 'training', etc.
+    resource_type VARCHAR(50) CHECK (resource_type IN (
+        'user', 'session', 'material', 'training', 'company', 'student', 'role_permission',
+        'project', 'project_participant', 'interview', 'meeting'
+    )), -- NULL=リソースに紐付かない操作
     resource_id UUID,
     details JSONB, -- 詳細情報（JSON形式）
     ip_address INET,
@@ -179,23 +219,30 @@ CREATE TABLE audit_logs (
     created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
 );
 
+-- users.company_id の外部キー（companies が users より後に作成されるため）
+ALTER TABLE users ADD CONSTRAINT fk_users_company_id
+    FOREIGN KEY (company_id) REFERENCES companies(id) ON DELETE SET NULL;
+
 -- ===== インデックス =====
 
 -- ユーザー関連
 CREATE INDEX idx_users_email ON users(email);
 CREATE INDEX idx_users_role ON users(role);
+CREATE INDEX idx_users_company_id ON users(company_id);
 
 -- セッション関連
-CREATE INDEX idx_sessions_token ON sessions(session_token);
 CREATE INDEX idx_sessions_user_id ON sessions(user_id);
 CREATE INDEX idx_sessions_expires_at ON sessions(expires_at);
+CREATE INDEX idx_sessions_user_ip ON sessions(user_id, ip_address);
 
 -- 企業・受講者関連
 CREATE INDEX idx_companies_name ON companies(name);
 CREATE INDEX idx_students_company_id ON students(company_id);
+CREATE INDEX idx_students_company_organization ON students(company_id, (TRIM(organization)));
 CREATE INDEX idx_students_email ON students(email);
 
 -- 教材関連
+CREATE UNIQUE INDEX idx_materials_url ON materials(url); -- 正規化済みURLの重複登録防止
 CREATE INDEX idx_materials_domain ON materials(domain);
 CREATE INDEX idx_materials_recommendation_level ON materials(recommendation_level);
 CREATE INDEX idx_materials_created_by ON materials(created_by);
@@ -204,6 +251,7 @@ CREATE INDEX idx_materials_title ON materials USING gin(to_tsvector('japanese',
 -- 研修コース関連
 CREATE INDEX idx_trainings_company_id ON trainings(company_id);
 CREATE INDEX idx_trainings_created_by ON trainings(created_by);
+CREATE INDEX idx_trainings_deleted_at ON trainings(deleted_at) WHERE deleted_at IS NOT NULL; -- ゴミ箱一覧用
 CREATE INDEX idx_trainings_title ON trainings USING gin(to_tsvector('japanese', title));
 CREATE INDEX idx_training_materials_training_id ON training_materials(training_id);
 CREATE INDEX idx_training_materials_material_id ON training_materials(material_id);
@@ -232,12 +280,14 @@ CREATE INDEX idx_meetings_project_id ON meetings(project_id);
 CREATE INDEX idx_meetings_instructor_id ON meetings(instructor_id);
 CREATE INDEX idx_meetings_scheduled_at ON meetings(scheduled_at);
 CREATE INDEX idx_meetings_created_by ON meetings(created_by);
+CREATE INDEX idx_meeting_note_revisions_meeting_id ON meeting_note_revisions(meeting_id, edited_at);
 
 -- 監査ログ関連
 CREATE INDEX idx_audit_logs_user_id ON audit_logs(user_id);
 CREATE INDEX idx_audit_logs_action ON audit_logs(action);
 CREATE INDEX idx_audit_logs_resource ON audit_logs(resource_type, resource_id);
 CREATE INDEX idx_audit_logs_created_at ON audit_logs(created_at);
+CREATE INDEX idx_audit_logs_ip_address ON audit_logs(ip_address, created_at DESC);
 
 -- ===== トリガー関数 =====
 
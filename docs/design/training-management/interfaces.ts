@@ -41,7 +41,10 @@ export interface User {
   email: string;
   name: string;
   role: UserRole;
+  company_id: UUID | null; // 所属企業
   password_hash: string;
+  failed_login_count: number; // 連続ログイン失敗回数
+  locked_until: ISODate | null; // アカウントロック解除日時
   created_at: ISODate;
   updated_at: ISODate;
 }
@@ -69,6 +72,7 @@ export interface Training {
   completion_criteria: string; // 完了条件
   company_id: UUID | null; // 企業紐付け（nullの場合は公開）
   created_by: UUID; // User.id
+  deleted_at: ISODate | null; // ソフトデリート日時（null=有効）
   created_at: ISODate;
   updated_at: ISODate;
 }
@@ -90,6 +94,7 @@ export interface Company {
   contact_person: string; // 担当者
   contact_email: string; // 連絡先
   chat_link: string | null; // チャットリンク
+  max_trainings: number | null; // 企業限定研修の作成数上限（null は既定値）
   created_at: ISODate;
   updated_at: ISODate;
 }
@@ -114,6 +119,8 @@ export interface Project {
   title: string;
   start_date: ISODate;
   end_date: ISODate;
+  max_interviews_per_participant: number | null; // null はシステム既定値（10回）
+  max_participants: number | null; // null は無制限
   created_by: UUID; // User.id
   created_at: ISODate;
   updated_at: ISODate;
@@ -137,6 +144,7 @@ export interface Interview {
   interviewer_id: UUID; // User.id
   scheduled_at: ISODate;
   status: InterviewStatus;
+  reschedule_count: number; // 日時変更回数
   notes: MarkdownText | null; // 面談記録
   created_at: ISODate;
   updated_at: ISODate;
@@ -153,15 +161,40 @@ export interface Meeting {
   instructor_id: UUID | null; // 任意参加の研修講師
   notes: MarkdownText | null; // 研修記録
   created_by: UUID; // User.id
+  updated_by: UUID | null; // 最終更新者
   created_at: ISODate;
   updated_at: ISODate;
 }
 
+// 定例会記録の履歴（更新前の notes）
+export interface MeetingNoteRevision {
+  id: UUID;
+  meeting_id: UUID;
+  notes: MarkdownText | null;
+  edited_by: UUID | null; // この版を置き換えた更新者
+  edited_at: ISODate;
+}
+
+// ロール別の許可ルート
+export interface RolePermission {
+  id: UUID;
+  role: UserRole;
+  route_pattern: string; // '/trainings/{id}' 形式
+  method: 'GET' | 'POST' | 'PUT' | 'PATCH' | 'DELETE';
+  created_by: UUID | null;
+  created_at: ISODate;
+}
+
 // セッション
 export interface Session {
   id: UUID;
   user_id: UUID;
-  session_token: string;
+  session_token_hash: string; // SHA-256（16進）。平文トークンはCookie/ログイン応答でのみ返す
+  csrf_token: string;
+  ip_address: string | null; // ログイン元IP
+  user_agent: string | null;
+  is_persistent: boolean; // 「ログイン状態を保持」による長期セッション
+  rotation_required: boolean; // 権限変更後、次のリクエストでトークンを再生成
   expires_at: ISODate;
   created_at: ISODate;
   last_accessed_at: ISODate;
@@ -182,15 +215,10 @@ export interface AuditLog {
 
 // ===== API リクエスト/レスポンス型 =====
 
-// 基本APIレスポンス
+// 基本APIレスポンス（成功時のみ。エラー時の本文は ErrorResponse）
 export interface ApiResponse<T = any> {
   success: boolean;
   data?: T;
-  error?: {
-    code: string;
-    message: string;
-    details?: Record<string, any>;
-  };
 }
 
 // ページネーション情報
@@ -201,14 +229,23 @@ export interface PaginationInfo {
   total_pages: number;
 }
 
+// 研修の公開区分フィルタ（public: 全社共通 / company: 企業限定 / all: 両方）
+export type TrainingVisibility = 'public' | 'company' | 'all';
+
+// 一覧に適用されたソート条件
+export interface AppliedSort {
+  sort: string;
+  order: 'asc' | 'desc';
+}
+
 // ページネーション付きレスポンス
 export interface PaginatedResponse<T> {
   success: boolean;
   data: T[];
   pagination: PaginationInfo;
-  error?: {
-    code: string;
-    message: string;
+  meta?: {
+    sort?: AppliedSort;
+    visibility?: TrainingVisibility;
   };
 }
 
@@ -217,11 +254,19 @@ export interface PaginatedResponse<T> {
 export interface LoginRequest {
   email: string;
   password: string;
+  remember_me?: boolean; // デフォルト: false
 }
 
 export interface LoginResponse {
-  user: Omit<User, 'password_hash'>;
-  session_token: string;
+  user: UserResponse;
+  session_token: string; // Bearer 用の平文トークン（Cookie の session_id と同じ値）
+  csrf_token: string;
+}
+
+export interface ChangePasswordRequest {
+  current_password: string;
+  new_password: string;
+  confirm_password: string;
 }
 
 // === ユーザー管理 ===
@@ -231,6 +276,7 @@ export interface CreateUserRequest {
   name: string;
   password: string;
   role: UserRole;
+  company_id?: UUID; // 管理者のみ指定可。未指定は企業に属さない
 }
 
 export interface UpdateUserRequest {
@@ -238,9 +284,10 @@ export interface UpdateUserRequest {
   name?: string;
   password?: string;
   role?: UserRole;
+  company_id?: UUID | null; // null で企業に属さない状態に戻す
 }
 
-export interface UserResponse extends Omit<User, 'password_hash'> {}
+export interface UserResponse extends Omit<User, 'password_hash' | 'failed_login_count'> {}
 
 // === 教材管理 ===
 
@@ -280,6 +327,16 @@ export interface CreateTrainingRequest {
   }[];
 }
 
+export interface BulkCreateTrainingsRequest {
+  mode?: 'atomic' | 'partial'; // デフォルト: atomic
+  trainings: Omit<CreateTrainingRequest, 'materials'>[]; // 最大100件
+}
+
+export interface BulkCreateTrainingsResponse {
+  created: { index: number; training_id: UUID }[];
+  failed: { index: number; field_errors: FieldError[] }[]; // 1件ごとに検出したエラーをすべて含む
+}
+
 export interface UpdateTrainingRequest {
   title?: string;
   description?: string;
@@ -287,9 +344,46 @@ export interface UpdateTrainingRequest {
   goals?: string;
   completion_criteria?: string;
   company_id?: UUID | null;
+  expected_updated_at: ISODate; // 楽観的ロック（編集開始時の updated_at）
+}
+
+// PATCH /trainings/:id（キーが無い項目は変更しない。空文字列は明示的なクリア）
+export type PatchTrainingRequest = Partial<Omit<UpdateTrainingRequest, 'expected_updated_at'>> &
+  Pick<UpdateTrainingRequest, 'expected_updated_at'>;
+
+// 企業別の研修数（GET /trainings/stats、管理者のみ）
+export interface TrainingCountByCompany {
+  company_id: UUID;
+  company_name: string;
+  training_count: number;
+}
+
+export interface TrainingStatsResponse {
+  success: boolean;
+  data: {
+    public_count: number; // 全社共通研修の数
+    companies: TrainingCountByCompany[];
+  };
+  pagination: PaginationInfo;
+}
+
+// 閲覧者による操作可否（UserTrainingPermissions で一覧・詳細共通に計算）
+export interface TrainingPermissions {
+  can_edit: boolean;
+  can_delete: boolean;
+}
+
+export interface TrainingListItem extends Training, TrainingPermissions {
+  material_count: number | null; // 集計失敗時のみ null
+  estimated_duration_days: number; // 教材の period_days 合計
+  company_name: string; // 公開研修は「全社共通」、見つからない企業は「（不明な企業）」
+  created_by_name: string; // 見つからないユーザーは「（退職者）」、nil UUID は「（不明なユーザー）」
 }
 
-export interface TrainingResponse extends Training {
+export interface TrainingResponse extends Training, TrainingPermissions {
+  description_html: string; // render_markdown_safe 済みのHTML（description も併せて返す）
+  estimated_duration_days: number; // 教材の period_days 合計
+  created_by_name: string; // TrainingListItem と同じ規則
   materials: (TrainingMaterial & {
     material: Material;
   })[];
@@ -297,6 +391,36 @@ export interface TrainingResponse extends Training {
   created_by_user: Pick<User, 'id' | 'name'>;
 }
 
+// 閲覧者情報（研修APIの user_context）
+export interface UserContext {
+  role: UserRole;
+  company_id: UUID | null;
+}
+
+export interface TrainingListResponse extends PaginatedResponse<TrainingListItem> {
+  user_context: UserContext;
+}
+
+// GET /trainings?format=ndjson の末尾行（各行は TrainingListItem）
+export interface TrainingNdjsonMeta {
+  meta: {
+    count: number;
+    complete: boolean; // false は途中でエラー終了
+    error_type?: ErrorType;
+  };
+}
+
+export interface TrainingDetailResponse extends ApiResponse<TrainingResponse> {
+  user_context: UserContext;
+}
+
+export type CreateTrainingResponse = ApiResponse<{
+  training_id: UUID | null; // dry_run 時は null
+  title: string;
+}> & {
+  dry_run?: true; // POST /trainings?dry_run=true の場合のみ
+};
+
 // === 企業管理 ===
 
 export interface CreateCompanyRequest {
@@ -313,10 +437,28 @@ export interface UpdateCompanyRequest {
   chat_link?: string;
 }
 
+export interface CompanyStats {
+  company_id: UUID;
+  student_count: number;
+  active_project_count: number;
+  training_count: number; // 企業限定研修の数
+  organizations: OrganizationCount[]; // 人数降順
+}
+
+// 組織（部署）別の受講者数
+export interface OrganizationCount {
+  organization: string; // 空文字は組織未設定（表示は「（未設定）」）
+  student_count: number;
+}
+
 export interface CompanyResponse extends Company {
   student_count: number;
 }
 
+export interface CompanyDetailResponse extends Company {
+  stats: CompanyStats;
+}
+
 // === 受講者管理 ===
 
 export interface CreateStudentRequest {
@@ -347,6 +489,7 @@ export interface CreateProjectRequest {
   title: string;
   start_date: ISODate;
   end_date: ISODate;
+  max_participants?: number; // 未指定は無制限
   participants: {
     student_id: UUID;
     status?: number; // デフォルト: 3 (average)
@@ -359,6 +502,7 @@ export interface UpdateProjectRequest {
   title?: string;
   start_date?: ISODate;
   end_date?: ISODate;
+  max_participants?: number | null; // null で無制限に戻す
 }
 
 export interface ProjectResponse extends Project {
@@ -368,6 +512,29 @@ export interface ProjectResponse extends Project {
   created_by_user: Pick<User, 'id' | 'name'>;
 }
 
+export interface ProjectDetailResponse extends ProjectResponse {
+  progress_summary: ProgressSummary;
+}
+
+export interface ProgressSummary {
+  project_id: UUID;
+  total_participants: number;
+  all_interviews_completed_count: number;
+  status_counts: Record<1 | 2 | 3 | 4 | 5, number>;
+}
+
+export interface BulkEnrollParticipantsRequest {
+  student_ids: UUID[];
+}
+
+export interface BulkEnrollParticipantsResponse {
+  enrolled: ProjectParticipant[];
+  skipped: {
+    student_id: UUID;
+    reason: 'company_mismatch' | 'already_enrolled' | 'student_not_found' | 'capacity_exceeded';
+  }[];
+}
+
 // === 面談管理 ===
 
 export interface CreateInterviewRequest {
@@ -381,6 +548,10 @@ export interface UpdateInterviewRequest {
   notes?: MarkdownText;
 }
 
+export interface RescheduleInterviewRequest {
+  scheduled_at: ISODate;
+}
+
 export interface InterviewResponse extends Interview {
   project_participant: ProjectParticipant & {
     project: Pick<Project, 'id' | 'title'>;
@@ -389,6 +560,13 @@ export interface InterviewResponse extends Interview {
   interviewer: Pick<User, 'id' | 'name'>;
 }
 
+// プロジェクト単位の面談一覧の各要素（find_by_project_id_with_names）
+export interface ProjectInterviewListItem extends Interview {
+  student_id: UUID;
+  student_name: string;
+  interviewer_name: string;
+}
+
 export interface InterviewAlert {
   project_participant_id: UUID;
   student_id: UUID;
@@ -409,6 +587,31 @@ export interface CreateMeetingRequest {
   instructor_id?: UUID;
 }
 
+// POST /meetings/series
+export interface CreateMeetingSeriesRequest {
+  mode?: 'atomic' | 'partial'; // デフォルト: atomic
+  project_id: UUID;
+  title: string;
+  scheduled_ats: ISODate[]; // 1〜52件。各日時に単発の定例会を作成
+  instructor_id?: UUID;
+}
+
+export interface MeetingSeriesConflict {
+  index: number; // scheduled_ats の添字
+  scheduled_at: ISODate;
+  reason: 'past' | 'conflict' | 'duplicate_in_request';
+  conflicting_meeting_id?: UUID; // reason が conflict の場合
+}
+
+export interface CreateMeetingSeriesResponse {
+  success: boolean;
+  data: {
+    created_count: number;
+    created: { index: number; meeting_id: UUID; scheduled_at: ISODate }[];
+    conflicts: MeetingSeriesConflict[];
+  };
+}
+
 export interface UpdateMeetingRequest {
   title?: string;
   scheduled_at?: ISODate;
@@ -424,6 +627,22 @@ export interface MeetingResponse extends Meeting {
   created_by_user: Pick<User, 'id' | 'name'>;
 }
 
+// === スケジュール ===
+
+export interface ScheduleEntry {
+  kind: 'meeting' | 'interview';
+  source_id: UUID; // Meeting.id または Interview.id
+  scheduled_at: ISODate;
+  title: string;
+  owner_id: UUID | null; // 講師または面談担当者
+  owner_name: string | null;
+}
+
+export interface ProjectScheduleResponse {
+  entries: ScheduleEntry[];
+  truncated: boolean; // 上限件数で打ち切った場合 true
+}
+
 // === フォーム バリデーション ===
 
 export interface ValidationError {
@@ -432,6 +651,9 @@ export interface ValidationError {
   code: string;
 }
 
+// Rust 側 trainings_utils::FieldError に対応
+export type FieldError = ValidationError;
+
 export interface FormValidationResponse {
   valid: boolean;
   errors: ValidationError[];
@@ -447,10 +669,17 @@ export interface MaterialSearchParams {
   per_page?: number;
 }
 
+export type SortOrder = 'asc' | 'desc';
+
 export interface TrainingSearchParams {
   q?: string;
   company_id?: UUID;
-  created_by?: UUID;
+  created_by?: UUID | 'me'; // 'me' は認証ユーザー。他者の指定は管理者のみ
+  sort?: 'created_at' | 'title' | 'material_count';
+  order?: SortOrder;
+  visibility?: TrainingVisibility;
+  include_deleted?: boolean; // 管理者のみ
+  format?: 'html' | 'json' | 'ndjson'; // ndjson はページングせず全件をストリーム出力
   page?: number;
   per_page?: number;
 }
@@ -495,37 +724,27 @@ export interface HTMXFragment {
 
 // === エラー定義 ===
 
-export interface ErrorDetails {
-  VALIDATION_ERROR: {
-    code: 'VALIDATION_ERROR';
-    message: string;
-    details: {
-      field_errors: ValidationError[];
-    };
-  };
-  NOT_FOUND: {
-    code: 'NOT_FOUND';
-    message: string;
-  };
-  UNAUTHORIZED: {
-    code: 'UNAUTHORIZED';
-    message: string;
-  };
-  FORBIDDEN: {
-    code: 'FORBIDDEN';
-    message: string;
-  };
-  CONFLICT: {
-    code: 'CONFLICT';
-    message: string;
-    details?: {
-      conflicting_resource?: string;
-    };
-  };
-  INTERNAL_ERROR: {
-    code: 'INTERNAL_ERROR';
-    message: string;
-  };
+export type ErrorType =
+  | 'VALIDATION_ERROR'
+  | 'NOT_FOUND'
+  | 'UNAUTHORIZED'
+  | 'FORBIDDEN'
+  | 'CONFLICT'
+  | 'INTERNAL_ERROR'
+  | 'DUPLICATE_MATERIAL'
+  | 'RATE_LIMITED'
+  | 'QUOTA_EXCEEDED';
+
+// JSON エラーレスポンス（RFC 7807, Content-Type: application/problem+json）
+export interface ErrorResponse {
+  type: string; // 'about:blank'
+  title: string;
+  status: number; // HTTPステータスと一致
+  detail: string;
+  error_type: ErrorType;
+  field_errors?: ValidationError[];
+  current?: Training; // CONFLICT（楽観的ロック）: 現在の研修。updated_at を expected_updated_at として再送できる
+  remaining?: number; // QUOTA_EXCEEDED: この企業で作成できる残り件数
 }
 
 // === セッション管理 ===
@@ -536,6 +755,18 @@ export interface SessionInfo {
   csrf_token: string;
 }
 
+// GET /auth/sessions の各要素（トークン類は含めない）
+export interface SessionSummary {
+  id: UUID;
+  created_at: ISODate; // ログイン日時
+  last_accessed_at: ISODate;
+  expires_at: ISODate;
+  ip_address: string | null;
+  user_agent: string | null;
+  is_current: boolean;
+  is_expired: boolean;
+}
+
 // === 設定・環境 ===
 
 export interface AppConfig {